@@ -1,14 +1,44 @@
+mod monitors;
+mod tray;
+mod updater;
+mod window_state;
+
+use tauri::Manager;
+use window_state::DisplayModeState;
+
 #[tauri::command]
-fn set_display_mode(window: tauri::Window, mode: String) {
+fn set_display_mode(
+    window: tauri::WebviewWindow,
+    state: tauri::State<DisplayModeState>,
+    mode: String,
+    monitor_index: Option<usize>,
+) {
+    if let Some(index) = monitor_index {
+        if matches!(mode.as_str(), "fullscreen" | "borderless" | "kiosk") {
+            monitors::move_to(&window, index);
+        }
+    }
+
     match mode.as_str() {
         "fullscreen" => {
             window.set_decorations(true).ok();
             window.set_fullscreen(true).ok();
+            window.set_always_on_top(false).ok();
+            window.set_visible_on_all_workspaces(false).ok();
         }
         "borderless" => {
             window.set_fullscreen(false).ok();
             window.set_decorations(false).ok();
             window.maximize().ok();
+            window.set_always_on_top(false).ok();
+            window.set_visible_on_all_workspaces(false).ok();
+        }
+        "kiosk" => {
+            window.set_fullscreen(false).ok();
+            window.set_decorations(false).ok();
+            window.maximize().ok();
+            window.set_always_on_top(true).ok();
+            window.set_visible_on_all_workspaces(true).ok();
         }
         "windowed" | _ => {
             window.set_fullscreen(false).ok();
@@ -16,14 +46,33 @@ fn set_display_mode(window: tauri::Window, mode: String) {
             window.unmaximize().ok();
             window.set_size(tauri::Size::Physical(tauri::PhysicalSize::new(1280, 720))).ok();
             window.center().ok();
+            window.set_always_on_top(false).ok();
+            window.set_visible_on_all_workspaces(false).ok();
         }
     }
+
+    let normalized = match mode.as_str() {
+        "fullscreen" => "fullscreen",
+        "borderless" => "borderless",
+        "kiosk" => "kiosk",
+        _ => "windowed",
+    };
+    *state.0.lock().unwrap() = normalized.to_string();
+    window_state::save_current(&window.app_handle(), &window, normalized, monitor_index);
 }
 
 #[tauri::command]
-fn toggle_fullscreen(window: tauri::Window) {
+fn get_display_mode(state: tauri::State<DisplayModeState>) -> String {
+    state.0.lock().unwrap().clone()
+}
+
+#[tauri::command]
+fn toggle_fullscreen(window: tauri::WebviewWindow) {
     let is_fs = window.is_fullscreen().unwrap_or(false);
-    window.set_fullscreen(!is_fs).ok();
+    let mode = if is_fs { "windowed" } else { "fullscreen" };
+    let state = window.app_handle().state::<DisplayModeState>();
+    let monitor_index = window_state::load(&window.app_handle()).monitor_index;
+    set_display_mode(window, state, mode.to_string(), monitor_index);
 }
 
 #[tauri::command]
@@ -31,11 +80,51 @@ fn is_fullscreen(window: tauri::Window) -> bool {
     window.is_fullscreen().unwrap_or(false)
 }
 
+#[tauri::command]
+fn list_monitors(window: tauri::WebviewWindow) -> Vec<monitors::MonitorInfo> {
+    monitors::list(&window)
+}
+
+#[tauri::command]
+fn set_always_on_top(window: tauri::Window, enabled: bool) {
+    window.set_always_on_top(enabled).ok();
+}
+
+#[tauri::command]
+fn set_visible_on_all_workspaces(window: tauri::Window, enabled: bool) {
+    window.set_visible_on_all_workspaces(enabled).ok();
+}
+
 #[tauri::command]
 fn quit(app: tauri::AppHandle) {
     app.exit(0);
 }
 
+#[tauri::command]
+fn hide_to_tray(app: tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        window.hide().ok();
+    }
+}
+
+#[tauri::command]
+fn show_from_tray(app: tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        window.show().ok();
+        window.set_focus().ok();
+    }
+}
+
+#[tauri::command]
+async fn check_for_update(app: tauri::AppHandle) -> Result<Option<updater::UpdateInfo>, String> {
+    updater::check(&app).await
+}
+
+#[tauri::command]
+async fn install_update(app: tauri::AppHandle) -> Result<(), String> {
+    updater::install(app).await
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // WebKit2GTK on Linux: enable hardware-accelerated rendering
@@ -46,6 +135,7 @@ pub fn run() {
 
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -54,13 +144,30 @@ pub fn run() {
                         .build(),
                 )?;
             }
+
+            let restored = window_state::load(app.handle());
+            if let Some(window) = app.get_webview_window("main") {
+                window_state::apply(&window, &restored);
+                tray::keep_alive_on_close(&window);
+            }
+            app.manage(DisplayModeState(std::sync::Mutex::new(restored.mode)));
+            tray::init(app.handle())?;
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             set_display_mode,
+            get_display_mode,
             toggle_fullscreen,
             is_fullscreen,
+            list_monitors,
+            set_always_on_top,
+            set_visible_on_all_workspaces,
             quit,
+            hide_to_tray,
+            show_from_tray,
+            check_for_update,
+            install_update,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use tauri::{PhysicalPosition, PhysicalSize, Position, Size, WebviewWindow};
+
+/// A monitor's geometry, as reported to the frontend for a monitor picker.
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    pub position: (i32, i32),
+    pub size: (u32, u32),
+    pub scale_factor: f64,
+}
+
+pub fn list(window: &WebviewWindow) -> Vec<MonitorInfo> {
+    window
+        .available_monitors()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|monitor| MonitorInfo {
+            name: monitor.name().cloned(),
+            position: (monitor.position().x, monitor.position().y),
+            size: (monitor.size().width, monitor.size().height),
+            scale_factor: monitor.scale_factor(),
+        })
+        .collect()
+}
+
+/// Move `window` onto the monitor at `index`, covering its full resolution.
+/// Does nothing if the index is out of range.
+pub fn move_to(window: &WebviewWindow, index: usize) {
+    let Ok(monitors) = window.available_monitors() else {
+        return;
+    };
+    let Some(monitor) = monitors.into_iter().nth(index) else {
+        return;
+    };
+
+    window
+        .set_position(Position::Physical(PhysicalPosition::new(
+            monitor.position().x,
+            monitor.position().y,
+        )))
+        .ok();
+    window
+        .set_size(Size::Physical(PhysicalSize::new(
+            monitor.size().width,
+            monitor.size().height,
+        )))
+        .ok();
+}
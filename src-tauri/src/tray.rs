@@ -0,0 +1,65 @@
+use tauri::{
+    menu::{Menu, MenuItem},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Manager, WebviewWindow, WindowEvent,
+};
+
+/// Build the tray icon and its Show / Toggle Fullscreen / Quit menu, and wire
+/// a left-click to toggle the main window's visibility.
+///
+/// On Linux this relies on the appindicator backend, so building the app
+/// requires the `libayatana-appindicator3-dev` package to be installed.
+pub fn init(app: &AppHandle) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+    let toggle_fullscreen =
+        MenuItem::with_id(app, "toggle-fullscreen", "Toggle Fullscreen", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &toggle_fullscreen, &quit])?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .tooltip("Les Aiguilles Blanches")
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "show" => crate::show_from_tray(app.clone()),
+            "toggle-fullscreen" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    crate::toggle_fullscreen(window);
+                }
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                let app = tray.app_handle();
+                let visible = app
+                    .get_webview_window("main")
+                    .and_then(|w| w.is_visible().ok())
+                    .unwrap_or(false);
+                if visible {
+                    crate::hide_to_tray(app.clone());
+                } else {
+                    crate::show_from_tray(app.clone());
+                }
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Hide `window` instead of letting its close button exit the process.
+pub fn keep_alive_on_close(window: &WebviewWindow) {
+    let window = window.clone();
+    window.clone().on_window_event(move |event| {
+        if let WindowEvent::CloseRequested { api, .. } = event {
+            api.prevent_close();
+            window.hide().ok();
+        }
+    });
+}
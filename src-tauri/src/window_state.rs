@@ -0,0 +1,127 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, Position, Size, WebviewWindow};
+
+/// Last known display mode, window geometry and target monitor, persisted to
+/// the app config dir so the kiosk restarts the way it was left.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowState {
+    pub mode: String,
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub monitor_index: Option<usize>,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self {
+            mode: "windowed".into(),
+            width: 1280,
+            height: 720,
+            x: 0,
+            y: 0,
+            monitor_index: None,
+        }
+    }
+}
+
+/// Currently active display mode, shared with the frontend via `get_display_mode`.
+pub struct DisplayModeState(pub Mutex<String>);
+
+fn state_file(app: &AppHandle) -> Option<std::path::PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join("window-state.json"))
+}
+
+pub fn load(app: &AppHandle) -> WindowState {
+    state_file(app)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(app: &AppHandle, state: &WindowState) {
+    let Some(path) = state_file(app) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(data) = serde_json::to_string_pretty(state) {
+        let _ = std::fs::write(path, data);
+    }
+}
+
+/// Apply a restored (or default) state to `window` before it is shown.
+pub fn apply(window: &WebviewWindow, state: &WindowState) {
+    if let Some(index) = state.monitor_index {
+        if matches!(state.mode.as_str(), "fullscreen" | "borderless" | "kiosk") {
+            crate::monitors::move_to(window, index);
+        }
+    }
+
+    match state.mode.as_str() {
+        "fullscreen" => {
+            window.set_decorations(true).ok();
+            window.set_fullscreen(true).ok();
+            window.set_always_on_top(false).ok();
+            window.set_visible_on_all_workspaces(false).ok();
+        }
+        "borderless" => {
+            window.set_fullscreen(false).ok();
+            window.set_decorations(false).ok();
+            window.maximize().ok();
+            window.set_always_on_top(false).ok();
+            window.set_visible_on_all_workspaces(false).ok();
+        }
+        "kiosk" => {
+            window.set_fullscreen(false).ok();
+            window.set_decorations(false).ok();
+            window.maximize().ok();
+            window.set_always_on_top(true).ok();
+            window.set_visible_on_all_workspaces(true).ok();
+        }
+        _ => {
+            window.set_fullscreen(false).ok();
+            window.set_decorations(true).ok();
+            window
+                .set_position(Position::Physical(PhysicalPosition::new(state.x, state.y)))
+                .ok();
+            window
+                .set_size(Size::Physical(PhysicalSize::new(state.width, state.height)))
+                .ok();
+        }
+    }
+}
+
+/// Snapshot `window`'s current geometry together with `mode` and `monitor_index`, and persist it.
+pub fn save_current(
+    app: &AppHandle,
+    window: &WebviewWindow,
+    mode: &str,
+    monitor_index: Option<usize>,
+) {
+    let size = window
+        .outer_size()
+        .unwrap_or(PhysicalSize::new(1280, 720));
+    let position = window
+        .outer_position()
+        .unwrap_or(PhysicalPosition::new(0, 0));
+    save(
+        app,
+        &WindowState {
+            mode: mode.to_string(),
+            width: size.width,
+            height: size.height,
+            x: position.x,
+            y: position.y,
+            monitor_index,
+        },
+    );
+}